@@ -1,4 +1,8 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Hex {
     pub q: i32,
     pub r: i32,
@@ -23,20 +27,35 @@ impl Hex {
             / 2
     }
 
-    pub fn within_radius(center: &Hex, radius: i32) -> Vec<Hex> {
-        let mut results = Vec::new();
-        for q in -radius..=radius {
-            let r1 = (-radius).max(-q - radius);
-            let r2 = radius.min(-q + radius);
-            for r in r1..=r2 {
-                results.push(Hex::new(center.q + q, center.r + r));
-            }
+    /// Ordered set of hexes a straight line from `self` to `other` passes
+    /// through, sampled by `lerp`-ing the cube coordinates at each of the
+    /// `distance(self, other)` steps and rounding back to a grid cell.
+    pub fn line_to(&self, other: &Hex) -> Vec<Hex> {
+        let n = self.distance(other);
+        if n == 0 {
+            return vec![*self];
         }
-        results
+
+        (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                let q = self.q as f64 * (1.0 - t) + other.q as f64 * t;
+                let r = self.r as f64 * (1.0 - t) + other.r as f64 * t;
+                FracHex::new(q, r).round()
+            })
+            .collect()
     }
 
-    pub fn neighbor(&self, direction: usize) -> Hex {
-        let directions = [
+    /// Hexes within `radius` of `center`, as a filled disc.
+    pub fn within_radius(center: &Hex, radius: i32) -> Vec<Hex> {
+        Hex::spiral(center, radius).collect()
+    }
+
+    /// Unit vector for one of the six hex directions, in the same order as
+    /// `neighbor` (0=East, 1=Northeast, 2=Northwest, 3=West, 4=Southwest,
+    /// 5=Southeast).
+    pub fn direction(direction: usize) -> Hex {
+        const DIRECTIONS: [(i32, i32); 6] = [
             (1, 0),  // East
             (1, -1), // Northeast
             (0, -1), // Northwest
@@ -44,7 +63,251 @@ impl Hex {
             (-1, 1), // Southwest
             (0, 1),  // Southeast
         ];
-        let (dq, dr) = directions[direction % 6];
-        Hex::new(self.q + dq, self.r + dr)
+        let (dq, dr) = DIRECTIONS[direction % 6];
+        Hex::new(dq, dr)
+    }
+
+    pub fn neighbor(&self, direction: usize) -> Hex {
+        *self + Hex::direction(direction)
+    }
+
+    /// Hexes at exactly `radius` steps from `center`, walked edge by edge
+    /// rather than allocated as a filled disc. Lazy: computes the next hex
+    /// on each call instead of materializing the whole ring up front.
+    pub fn ring(center: &Hex, radius: i32) -> Ring {
+        Ring::new(*center, radius)
+    }
+
+    /// `center` followed by `Hex::ring(center, 1)`, `Hex::ring(center, 2)`,
+    /// ..., `Hex::ring(center, radius)`. Lazy, so sensor-range or
+    /// blast-radius checks don't need to allocate the whole disc.
+    pub fn spiral(center: &Hex, radius: i32) -> Spiral {
+        Spiral::new(*center, radius)
+    }
+
+    pub fn scale(&self, k: i32) -> Hex {
+        Hex::new(self.q * k, self.r * k)
+    }
+
+    /// Rotates this hex 60° about the origin. In cube coordinates
+    /// `(x,y,z) -> (-z,-x,-y)`; in axial terms that's `(q,r) -> (-r, q+r)`.
+    pub fn rotate_left(&self) -> Hex {
+        Hex::new(-self.r, self.q + self.r)
+    }
+
+    /// Rotates this hex 60° about the origin in the opposite direction of
+    /// `rotate_left`. In cube coordinates `(x,y,z) -> (-y,-z,-x)`; in axial
+    /// terms that's `(q,r) -> (q+r, -q)`.
+    pub fn rotate_right(&self) -> Hex {
+        Hex::new(self.q + self.r, -self.q)
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Hex {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Hex {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, rhs: Hex) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i32) -> Hex {
+        self.scale(rhs)
+    }
+}
+
+impl MulAssign<i32> for Hex {
+    fn mul_assign(&mut self, rhs: i32) {
+        self.q *= rhs;
+        self.r *= rhs;
+    }
+}
+
+/// Fractional axial coordinate, used as an intermediate when converting
+/// floating-point input (mouse clicks, interpolated lines) to a grid cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FracHex {
+    pub q: f64,
+    pub r: f64,
+}
+
+impl FracHex {
+    pub fn new(q: f64, r: f64) -> Self {
+        FracHex { q, r }
+    }
+
+    /// Rounds to the nearest valid `Hex`. Rounds each cube coordinate
+    /// independently, then fixes up the component with the largest
+    /// rounding delta so the three still sum to zero.
+    pub fn round(&self) -> Hex {
+        let x = self.q;
+        let z = self.r;
+        let y = -x - z;
+
+        let mut rx = x.round();
+        let mut ry = y.round();
+        let mut rz = z.round();
+
+        let x_diff = (rx - x).abs();
+        let y_diff = (ry - y).abs();
+        let z_diff = (rz - z).abs();
+
+        if x_diff > y_diff && x_diff > z_diff {
+            rx = -ry - rz;
+        } else if y_diff > z_diff {
+            ry = -rx - rz;
+        } else {
+            rz = -rx - ry;
+        }
+
+        Hex::new(rx as i32, rz as i32)
+    }
+}
+
+/// Lazy iterator over the hexes at a fixed radius from a center, produced
+/// by [`Hex::ring`].
+pub struct Ring {
+    current: Hex,
+    direction: usize,
+    steps_taken: i32,
+    radius: i32,
+    emitted: i32,
+    total: i32,
+}
+
+impl Ring {
+    fn new(center: Hex, radius: i32) -> Self {
+        let total = if radius < 0 {
+            0
+        } else if radius == 0 {
+            1
+        } else {
+            radius * 6
+        };
+        let current = if radius > 0 {
+            center + Hex::direction(4).scale(radius)
+        } else {
+            center
+        };
+
+        Ring {
+            current,
+            direction: 0,
+            steps_taken: 0,
+            radius,
+            emitted: 0,
+            total,
+        }
+    }
+}
+
+impl Iterator for Ring {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Hex> {
+        if self.emitted >= self.total {
+            return None;
+        }
+
+        let result = self.current;
+        self.emitted += 1;
+
+        if self.emitted < self.total {
+            self.current = self.current.neighbor(self.direction);
+            self.steps_taken += 1;
+            if self.steps_taken == self.radius {
+                self.steps_taken = 0;
+                self.direction += 1;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+/// Lazy iterator over `center` followed by rings `1..=radius`, produced by
+/// [`Hex::spiral`].
+pub struct Spiral {
+    center: Hex,
+    radius: i32,
+    center_emitted: bool,
+    current_radius: i32,
+    ring: Option<Ring>,
+}
+
+impl Spiral {
+    fn new(center: Hex, radius: i32) -> Self {
+        Spiral {
+            center,
+            radius,
+            center_emitted: false,
+            current_radius: 1,
+            ring: None,
+        }
+    }
+}
+
+impl Iterator for Spiral {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Hex> {
+        if self.radius < 0 {
+            return None;
+        }
+
+        if !self.center_emitted {
+            self.center_emitted = true;
+            return Some(self.center);
+        }
+
+        loop {
+            if self.current_radius > self.radius {
+                return None;
+            }
+
+            if self.ring.is_none() {
+                self.ring = Some(Ring::new(self.center, self.current_radius));
+            }
+
+            if let Some(hex) = self.ring.as_mut().unwrap().next() {
+                return Some(hex);
+            }
+
+            self.ring = None;
+            self.current_radius += 1;
+        }
     }
 }
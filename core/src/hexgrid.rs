@@ -0,0 +1,71 @@
+use crate::Hex;
+
+/// Bounds-checked, typed-cell container over a hexagonal playfield.
+/// `Hex::to_id` computes a dense index assuming a rectangular
+/// `(2*num_rings+1)^2` backing array, which includes corner cells that
+/// aren't part of a true hexagonal board; `HexGrid` validates coordinates
+/// against the hexagonal bound instead of trusting a possibly-bogus index.
+#[derive(Debug, Clone)]
+pub struct HexGrid<T> {
+    num_rings: u32,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> HexGrid<T> {
+    /// Creates a grid of `num_rings` rings around the origin, with every
+    /// backing cell (including the out-of-bounds corners) set to `fill`.
+    pub fn new(num_rings: u32, fill: T) -> Self {
+        let max_coord = num_rings as i32 * 2;
+        let size = ((max_coord + 1) * (max_coord + 1)) as usize;
+        HexGrid {
+            num_rings,
+            cells: vec![fill; size],
+        }
+    }
+}
+
+impl<T> HexGrid<T> {
+    /// Whether `hex`'s cube distance from the center is at most
+    /// `num_rings`, i.e. it's part of the true hexagonal board rather than
+    /// one of the backing array's addressable corners.
+    pub fn is_valid(&self, hex: Hex) -> bool {
+        hex.distance(&Hex::new(0, 0)) <= self.num_rings
+    }
+
+    fn index(&self, hex: Hex) -> Option<usize> {
+        if !self.is_valid(hex) {
+            return None;
+        }
+        Some(hex.to_id(self.num_rings))
+    }
+
+    pub fn get(&self, hex: Hex) -> Option<&T> {
+        let idx = self.index(hex)?;
+        Some(&self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        let idx = self.index(hex)?;
+        Some(&mut self.cells[idx])
+    }
+
+    /// Sets the cell at `hex`, returning `false` without writing anything
+    /// if `hex` is outside the hexagonal playfield.
+    pub fn set(&mut self, hex: Hex, value: T) -> bool {
+        match self.index(hex) {
+            Some(idx) => {
+                self.cells[idx] = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterates over the in-bounds `(Hex, &T)` cells of the hexagonal
+    /// playfield, skipping the backing array's out-of-bounds corners.
+    pub fn iter(&self) -> impl Iterator<Item = (Hex, &T)> + '_ {
+        Hex::within_radius(&Hex::new(0, 0), self.num_rings as i32)
+            .into_iter()
+            .map(move |hex| (hex, &self.cells[hex.to_id(self.num_rings)]))
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+use crate::hex::FracHex;
+use crate::Hex;
+
+/// Wrapper around `HashSet<Hex>` providing composable set algebra for
+/// territory and control-zone logic: "which hexes are contested", "the
+/// border between two empires", "cells in range of any of my fleets".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HexSet {
+    hexes: HashSet<Hex>,
+}
+
+impl HexSet {
+    pub fn new() -> Self {
+        HexSet {
+            hexes: HashSet::new(),
+        }
+    }
+
+    pub fn from_hexes(hexes: impl IntoIterator<Item = Hex>) -> Self {
+        HexSet {
+            hexes: hexes.into_iter().collect(),
+        }
+    }
+
+    /// The six neighbors of `center` (not including `center` itself).
+    pub fn neighbors_of(center: Hex) -> Self {
+        HexSet::from_hexes((0..6).map(|dir| center.neighbor(dir)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.hexes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hexes.is_empty()
+    }
+
+    pub fn contains(&self, hex: &Hex) -> bool {
+        self.hexes.contains(hex)
+    }
+
+    pub fn insert(&mut self, hex: Hex) -> bool {
+        self.hexes.insert(hex)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Hex> {
+        self.hexes.iter()
+    }
+
+    pub fn intersection(&self, other: &HexSet) -> HexSet {
+        HexSet::from_hexes(self.hexes.intersection(&other.hexes).copied())
+    }
+
+    pub fn union(&self, other: &HexSet) -> HexSet {
+        HexSet::from_hexes(self.hexes.union(&other.hexes).copied())
+    }
+
+    pub fn difference(&self, other: &HexSet) -> HexSet {
+        HexSet::from_hexes(self.hexes.difference(&other.hexes).copied())
+    }
+
+    /// Members that have at least one `neighbor` outside the set, i.e. the
+    /// boundary facing unclaimed or foreign territory.
+    pub fn border(&self) -> HexSet {
+        HexSet::from_hexes(
+            self.hexes
+                .iter()
+                .copied()
+                .filter(|&hex| (0..6).any(|dir| !self.hexes.contains(&hex.neighbor(dir)))),
+        )
+    }
+
+    /// Unions every member's `Hex::within_radius(member, radius)`.
+    pub fn expand(&self, radius: i32) -> HexSet {
+        let mut expanded = HashSet::new();
+        for &hex in &self.hexes {
+            expanded.extend(Hex::within_radius(&hex, radius));
+        }
+        HexSet { hexes: expanded }
+    }
+
+    /// Average coordinate of all members, rounded to the nearest `Hex`.
+    /// `None` if the set is empty.
+    pub fn centroid(&self) -> Option<Hex> {
+        if self.hexes.is_empty() {
+            return None;
+        }
+
+        let (sum_q, sum_r) = self
+            .hexes
+            .iter()
+            .fold((0i64, 0i64), |(sq, sr), hex| (sq + hex.q as i64, sr + hex.r as i64));
+        let n = self.hexes.len() as f64;
+
+        Some(FracHex::new(sum_q as f64 / n, sum_r as f64 / n).round())
+    }
+
+    /// Inclusive `(min, max)` corners of the axial bounding box around all
+    /// members. `None` if the set is empty.
+    pub fn bounding_box(&self) -> Option<(Hex, Hex)> {
+        let mut hexes = self.hexes.iter();
+        let first = *hexes.next()?;
+        let (mut min_q, mut min_r) = (first.q, first.r);
+        let (mut max_q, mut max_r) = (first.q, first.r);
+
+        for hex in hexes {
+            min_q = min_q.min(hex.q);
+            min_r = min_r.min(hex.r);
+            max_q = max_q.max(hex.q);
+            max_r = max_r.max(hex.r);
+        }
+
+        Some((Hex::new(min_q, min_r), Hex::new(max_q, max_r)))
+    }
+}
+
+impl FromIterator<Hex> for HexSet {
+    fn from_iter<I: IntoIterator<Item = Hex>>(iter: I) -> Self {
+        HexSet::from_hexes(iter)
+    }
+}
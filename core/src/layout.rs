@@ -0,0 +1,64 @@
+use crate::hex::FracHex;
+use crate::Hex;
+
+/// Hex orientation for screen-space layout: pointy-top hexes have a vertex
+/// pointing up, flat-top hexes have an edge along the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    PointyTop,
+    FlatTop,
+}
+
+/// Maps between axial `Hex` coordinates and pixel/screen space, so a UI or
+/// map editor can render the star map and translate mouse clicks into
+/// tile selection without reinventing hex-to-pixel math.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layout {
+    pub orientation: Orientation,
+    pub size: (f64, f64),
+    pub origin: (f64, f64),
+}
+
+impl Layout {
+    pub fn new(orientation: Orientation, size: (f64, f64), origin: (f64, f64)) -> Self {
+        Layout {
+            orientation,
+            size,
+            origin,
+        }
+    }
+
+    /// Pixel-space center of `hex`.
+    pub fn hex_to_pixel(&self, hex: Hex) -> (f64, f64) {
+        let (size_x, size_y) = self.size;
+        let (q, r) = (hex.q as f64, hex.r as f64);
+
+        let (x, y) = match self.orientation {
+            Orientation::PointyTop => (
+                size_x * (3f64.sqrt() * q + 3f64.sqrt() / 2.0 * r),
+                size_y * (3.0 / 2.0 * r),
+            ),
+            Orientation::FlatTop => (
+                size_x * (3.0 / 2.0 * q),
+                size_y * (3f64.sqrt() / 2.0 * q + 3f64.sqrt() * r),
+            ),
+        };
+
+        (x + self.origin.0, y + self.origin.1)
+    }
+
+    /// The `Hex` containing pixel-space `point`, via the inverse layout
+    /// matrix fed through `FracHex::round`.
+    pub fn pixel_to_hex(&self, point: (f64, f64)) -> Hex {
+        let (size_x, size_y) = self.size;
+        let x = (point.0 - self.origin.0) / size_x;
+        let y = (point.1 - self.origin.1) / size_y;
+
+        let (q, r) = match self.orientation {
+            Orientation::PointyTop => (3f64.sqrt() / 3.0 * x - 1.0 / 3.0 * y, 2.0 / 3.0 * y),
+            Orientation::FlatTop => (2.0 / 3.0 * x, -1.0 / 3.0 * x + 3f64.sqrt() / 3.0 * y),
+        };
+
+        FracHex::new(q, r).round()
+    }
+}
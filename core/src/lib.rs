@@ -1,12 +1,21 @@
 #[allow(dead_code)]
 pub mod fleet;
 pub mod hex;
+pub mod hexgrid;
+pub mod hexset;
+pub mod layout;
+pub mod pathfinding;
 pub mod ship;
 pub mod starmap;
 pub mod system;
 
 pub use fleet::Fleet;
 pub use hex::Hex;
+pub use hexgrid::HexGrid;
+pub use hexset::HexSet;
+pub use layout::{Layout, Orientation};
 pub use ship::{Ship, ShipType};
-pub use starmap::{JumpLane, LaneType, StarMap};
+pub use starmap::{
+    JumpLane, LaneType, MoveMetric, PrecomputedRoutes, RouteMode, StarMap, TraversalClass,
+};
 pub use system::System;
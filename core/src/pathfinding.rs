@@ -0,0 +1,86 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::Hex;
+
+/// Least-cost path between `start` and `goal` over a hex grid. `cost(from,
+/// to)` gives the weight of stepping from `from` onto the neighboring hex
+/// `to`, or `None` if `to` is impassable. Uses A* with `Hex::distance` as
+/// the heuristic, which never overestimates since the minimum step cost
+/// is 1. Returns the path (inclusive of `start` and `goal`) alongside its
+/// total cost.
+pub fn astar(
+    start: Hex,
+    goal: Hex,
+    mut cost: impl FnMut(Hex, Hex) -> Option<u32>,
+) -> Option<(Vec<Hex>, u32)> {
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: HashMap<Hex, Hex> = HashMap::new();
+    let mut g_score: HashMap<Hex, u32> = HashMap::new();
+
+    g_score.insert(start, 0);
+    open_set.push(Reverse((start.distance(&goal), start)));
+
+    while let Some(Reverse((_, current))) = open_set.pop() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut node = current;
+            while let Some(&prev) = came_from.get(&node) {
+                path.push(prev);
+                node = prev;
+            }
+            path.reverse();
+            return Some((path, g_score[&goal]));
+        }
+
+        for direction in 0..6 {
+            let neighbor = current.neighbor(direction);
+            let Some(step_cost) = cost(current, neighbor) else {
+                continue;
+            };
+
+            let tentative_g = g_score[&current] + step_cost;
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
+                came_from.insert(neighbor, current);
+                g_score.insert(neighbor, tentative_g);
+                let f_score = tentative_g + neighbor.distance(&goal);
+                open_set.push(Reverse((f_score, neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra expansion from `start` with no single goal, equivalent to
+/// `astar` with the heuristic fixed at zero. Returns the cost to reach
+/// every hex reachable from `start`, for flow-field / reachable-area
+/// queries where there isn't one destination to aim for.
+pub fn dijkstra(start: Hex, mut cost: impl FnMut(Hex, Hex) -> Option<u32>) -> HashMap<Hex, u32> {
+    let mut open_set = BinaryHeap::new();
+    let mut g_score: HashMap<Hex, u32> = HashMap::new();
+
+    g_score.insert(start, 0);
+    open_set.push(Reverse((0u32, start)));
+
+    while let Some(Reverse((dist, current))) = open_set.pop() {
+        if dist > *g_score.get(&current).unwrap_or(&u32::MAX) {
+            continue;
+        }
+
+        for direction in 0..6 {
+            let neighbor = current.neighbor(direction);
+            let Some(step_cost) = cost(current, neighbor) else {
+                continue;
+            };
+
+            let tentative_g = dist + step_cost;
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
+                g_score.insert(neighbor, tentative_g);
+                open_set.push(Reverse((tentative_g, neighbor)));
+            }
+        }
+    }
+
+    g_score
+}
@@ -2,13 +2,16 @@ use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use rand::seq::SliceRandom;
 use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
 use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 
 use crate::{Fleet, Hex, System};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LaneType {
     Major,
     Minor,
@@ -25,7 +28,45 @@ impl LaneType {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Search strategy for [`StarMap::route`]. `Bfs` ignores lane weights and
+/// the heuristic (uniform edge cost, fewest jumps). `Greedy` orders the
+/// frontier purely by the hex-distance heuristic to the goal. `AStar`
+/// matches [`StarMap::astar`]'s `g + heuristic` behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteMode {
+    Bfs,
+    Greedy,
+    AStar,
+}
+
+/// Cost metric for [`StarMap::reachable`]. `Weighted` accumulates
+/// [`LaneType::weight`] per lane (so Major lanes are cheaper); `Hops`
+/// counts each traversal as 1 regardless of lane type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveMetric {
+    Weighted,
+    Hops,
+}
+
+/// `Fleet::can_traverse` only branches on whether restricted lanes are
+/// usable, so there are effectively two traversal classes worth caching
+/// distances for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TraversalClass {
+    /// Crippled military ships and spacelift-only fleets: restricted lanes
+    /// are off-limits.
+    Standard,
+    /// Any fleet whose ships can all cross restricted lanes.
+    RestrictedCapable,
+}
+
+impl TraversalClass {
+    fn allows(&self, lane_type: LaneType) -> bool {
+        !matches!((self, lane_type), (TraversalClass::Standard, LaneType::Restricted))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JumpLane {
     pub source: usize,
     pub destination: usize,
@@ -38,6 +79,22 @@ pub struct StarMap {
     pub lanes: Vec<JumpLane>,
     pub graph: Graph<(), LaneType, Undirected>,
     system_id_to_node: HashMap<usize, NodeIndex>,
+    node_to_system_id: HashMap<NodeIndex, usize>,
+    /// `Hex -> system id` index so neighbor resolution doesn't need to
+    /// scan `systems`.
+    coord_index: HashMap<Hex, usize>,
+    player_count: usize,
+    num_rings: u32,
+    hub_id: usize,
+}
+
+/// On-disk representation of a [`StarMap`]. The `petgraph` graph isn't
+/// serialized directly; `StarMap::load` rebuilds it from these systems and
+/// lanes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StarMapSnapshot {
+    systems: Vec<System>,
+    lanes: Vec<JumpLane>,
     player_count: usize,
     num_rings: u32,
     hub_id: usize,
@@ -50,17 +107,66 @@ impl StarMap {
             lanes: Vec::new(),
             graph: Graph::new_undirected(),
             system_id_to_node: HashMap::new(),
+            node_to_system_id: HashMap::new(),
+            coord_index: HashMap::new(),
             player_count,
             num_rings: player_count as u32,
             hub_id: 0, // Will be updated when the hub is created
         }
     }
 
+    /// Saves the galaxy so it can be reloaded with [`StarMap::load`]
+    /// instead of regenerated, since `generate_lanes` draws from
+    /// `thread_rng` and isn't reproducible across runs.
+    pub fn save(&self, path: impl AsRef<Path>) -> bincode::Result<()> {
+        let snapshot = StarMapSnapshot {
+            systems: self.systems.values().cloned().collect(),
+            lanes: self.lanes.clone(),
+            player_count: self.player_count,
+            num_rings: self.num_rings,
+            hub_id: self.hub_id,
+        };
+        let file = std::fs::File::create(path)?;
+        bincode::serialize_into(file, &snapshot)
+    }
+
+    /// Loads a galaxy saved with [`StarMap::save`], rebuilding the
+    /// `petgraph` graph and id/node maps from the serialized systems and
+    /// lanes rather than storing the graph directly.
+    pub fn load(path: impl AsRef<Path>) -> bincode::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let snapshot: StarMapSnapshot = bincode::deserialize_from(file)?;
+
+        let mut star_map = StarMap {
+            systems: HashMap::new(),
+            lanes: Vec::new(),
+            graph: Graph::new_undirected(),
+            system_id_to_node: HashMap::new(),
+            node_to_system_id: HashMap::new(),
+            coord_index: HashMap::new(),
+            player_count: snapshot.player_count,
+            num_rings: snapshot.num_rings,
+            hub_id: snapshot.hub_id,
+        };
+
+        for system in snapshot.systems {
+            star_map.add_system(system);
+        }
+        for lane in snapshot.lanes {
+            star_map.add_lane(lane.source, lane.destination, lane.lane_type);
+        }
+
+        Ok(star_map)
+    }
+
     pub fn add_system(&mut self, system: System) {
         let system_id = system.id;
+        let coords = system.coords;
         self.systems.insert(system_id, system);
         let node_index = self.graph.add_node(());
         self.system_id_to_node.insert(system_id, node_index);
+        self.node_to_system_id.insert(node_index, system_id);
+        self.coord_index.insert(coords, system_id);
     }
 
     pub fn populate(&mut self) {
@@ -146,7 +252,7 @@ impl StarMap {
         directions
             .filter(|&dir| {
                 let neighbor = hex.neighbor(dir);
-                self.systems.values().any(|s| s.coords == neighbor)
+                self.coord_index.contains_key(&neighbor)
             })
             .count()
     }
@@ -207,11 +313,11 @@ impl StarMap {
             .expect("Hub system not found")
             .coords;
 
-        let neighbor_ids = self
-            .systems
-            .values()
-            .filter(|s| s.ring == 1 && s.coords.distance(&hub_coords) == 1)
-            .map(|s| s.id)
+        let neighbor_ids = (0..6)
+            .filter_map(|dir| {
+                let neighbor_coords = hub_coords.neighbor(dir);
+                self.coord_index.get(&neighbor_coords).copied()
+            })
             .collect::<Vec<usize>>();
 
         if neighbor_ids.len() != 6 {
@@ -230,10 +336,7 @@ impl StarMap {
         let mut neighbor_ids = (0..6)
             .filter_map(|dir| {
                 let neighbor_coords = system_coords.neighbor(dir);
-                self.systems
-                    .values()
-                    .find(|s| s.coords == neighbor_coords)
-                    .map(|s| s.id)
+                self.coord_index.get(&neighbor_coords).copied()
             })
             .collect::<Vec<usize>>();
 
@@ -268,10 +371,7 @@ impl StarMap {
         let neighbor_ids = (0..6)
             .filter_map(|dir| {
                 let neighbor_coords = system_coords.neighbor(dir);
-                self.systems
-                    .values()
-                    .find(|s| s.coords == neighbor_coords)
-                    .map(|s| s.id)
+                self.coord_index.get(&neighbor_coords).copied()
             })
             .filter(|&nid| nid != id)
             .collect::<Vec<usize>>();
@@ -309,10 +409,7 @@ impl StarMap {
         let neighbor_ids = (0..6)
             .filter_map(|dir| {
                 let neighbor_coords = system_coords.neighbor(dir);
-                self.systems
-                    .values()
-                    .find(|s| s.coords == neighbor_coords)
-                    .map(|s| s.id)
+                self.coord_index.get(&neighbor_coords).copied()
             })
             .filter(|&nid| nid != id)
             .collect::<Vec<usize>>();
@@ -360,31 +457,48 @@ impl StarMap {
         }
     }
 
+    /// Id of the galaxy's central hub system.
+    pub fn hub_id(&self) -> usize {
+        self.hub_id
+    }
+
     pub fn get_system_id_by_node_index(&self, node_index: NodeIndex) -> usize {
         *self
-            .system_id_to_node
-            .iter()
-            .find(|(_, &n)| n == node_index)
+            .node_to_system_id
+            .get(&node_index)
             .expect("NodeIndex not found in mapping")
-            .0
     }
 
+    /// Thin wrapper over [`StarMap::route`] in [`RouteMode::AStar`] mode
+    /// with no beam limit, kept as the ergonomic entry point for plain A*
+    /// pathfinding.
     pub fn astar(&self, start: &System, goal: &System, fleet: &Fleet) -> Option<Vec<System>> {
+        self.route(start, goal, fleet, RouteMode::AStar, None)
+    }
+
+    /// Generalized pathfinding entry point: trades path quality for speed
+    /// via `mode`, and bounds the frontier to the best `beam` candidates
+    /// (by the mode's priority) when `beam` is `Some`, so routing across a
+    /// large galaxy is bounded rather than exploring the whole graph.
+    /// `Fleet::can_traverse` gating applies in every mode.
+    pub fn route(
+        &self,
+        start: &System,
+        goal: &System,
+        fleet: &Fleet,
+        mode: RouteMode,
+        beam: Option<usize>,
+    ) -> Option<Vec<System>> {
         let mut open_set = BinaryHeap::new();
         let mut came_from = HashMap::new();
         let mut g_score = HashMap::new();
-        let mut f_score = HashMap::new();
 
         let start_id = start.id;
         let goal_id = goal.id;
 
-        g_score.insert(start_id, 0);
-        f_score.insert(
-            start_id,
-            self.systems[&start_id].coords.distance(&goal.coords),
-        );
-
-        open_set.push(Reverse((f_score[&start_id], start_id)));
+        g_score.insert(start_id, 0u32);
+        let start_h = start.coords.distance(&goal.coords);
+        open_set.push(Reverse((Self::route_priority(mode, 0, start_h), start_id)));
 
         while let Some(Reverse((_, current))) = open_set.pop() {
             if current == goal_id {
@@ -409,25 +523,107 @@ impl StarMap {
                     continue;
                 }
 
-                let tentative_g_score = g_score[&current] + lane_type.weight();
+                let edge_cost = match mode {
+                    RouteMode::Bfs => 1,
+                    RouteMode::Greedy | RouteMode::AStar => lane_type.weight(),
+                };
+
+                let tentative_g_score = g_score[&current] + edge_cost;
                 if tentative_g_score < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
                     came_from.insert(neighbor, current);
                     g_score.insert(neighbor, tentative_g_score);
-                    f_score.insert(
-                        neighbor,
-                        tentative_g_score
-                            + self.systems[&neighbor]
-                                .coords
-                                .distance(&self.systems[&goal_id].coords),
-                    );
-                    open_set.push(Reverse((f_score[&neighbor], neighbor)));
+                    let h = self.systems[&neighbor]
+                        .coords
+                        .distance(&self.systems[&goal_id].coords);
+                    let priority = Self::route_priority(mode, tentative_g_score, h);
+                    open_set.push(Reverse((priority, neighbor)));
                 }
             }
+
+            if let Some(beam) = beam {
+                Self::prune_to_beam(&mut open_set, beam);
+            }
         }
 
         None
     }
 
+    fn route_priority(mode: RouteMode, g: u32, h: u32) -> u32 {
+        match mode {
+            RouteMode::Bfs => g,
+            RouteMode::Greedy => h,
+            RouteMode::AStar => g + h,
+        }
+    }
+
+    /// Keeps only the `beam` best-scoring (lowest priority) entries in the
+    /// open set, dropping the rest so memory/time stay bounded.
+    fn prune_to_beam(open_set: &mut BinaryHeap<Reverse<(u32, usize)>>, beam: usize) {
+        if open_set.len() <= beam {
+            return;
+        }
+        let mut kept = Vec::with_capacity(beam);
+        while kept.len() < beam {
+            match open_set.pop() {
+                Some(item) => kept.push(item),
+                None => break,
+            }
+        }
+        open_set.clear();
+        open_set.extend(kept);
+    }
+
+    /// Every system reachable from `origin` within `budget` movement cost,
+    /// and the minimum cost to reach each, via a Dijkstra expansion that
+    /// stops relaxing once accumulated cost exceeds `budget`. Gives the
+    /// turn engine a "where can this fleet go this turn" query and a basis
+    /// for movement-range UI highlighting. Respects `Fleet::can_traverse`.
+    pub fn reachable(
+        &self,
+        origin: &System,
+        fleet: &Fleet,
+        budget: u32,
+        metric: MoveMetric,
+    ) -> HashMap<usize, u32> {
+        let mut cost: HashMap<usize, u32> = HashMap::new();
+        let mut open_set = BinaryHeap::new();
+
+        cost.insert(origin.id, 0);
+        open_set.push(Reverse((0u32, origin.id)));
+
+        while let Some(Reverse((dist, current))) = open_set.pop() {
+            if dist > *cost.get(&current).unwrap_or(&u32::MAX) {
+                continue;
+            }
+
+            let current_node = *self.system_id_to_node.get(&current).unwrap();
+            for edge in self.graph.edges(current_node) {
+                let lane_type = *edge.weight();
+                if !fleet.can_traverse(lane_type) {
+                    continue;
+                }
+
+                let step_cost = match metric {
+                    MoveMetric::Weighted => lane_type.weight(),
+                    MoveMetric::Hops => 1,
+                };
+
+                let tentative_cost = dist + step_cost;
+                if tentative_cost > budget {
+                    continue;
+                }
+
+                let neighbor = self.get_system_id_by_node_index(edge.target());
+                if tentative_cost < *cost.get(&neighbor).unwrap_or(&u32::MAX) {
+                    cost.insert(neighbor, tentative_cost);
+                    open_set.push(Reverse((tentative_cost, neighbor)));
+                }
+            }
+        }
+
+        cost
+    }
+
     pub fn is_connected(&self) -> bool {
         let mut visited = HashSet::new();
         let mut stack = Vec::new();
@@ -447,4 +643,283 @@ impl StarMap {
 
         visited.len() == self.systems.len()
     }
+
+    /// Maximum number of free (non-pinned) stops `plan_tour` will permute;
+    /// the start counts as a free stop unless `pin_first` is set. Ordering
+    /// search is factorial in this count, so larger tours are rejected
+    /// rather than explored.
+    pub const MAX_TOUR_WAYPOINTS: usize = 10;
+
+    /// Finds the cheapest ordering in which `fleet` can visit `start` and
+    /// every system in `waypoints`, stitching the per-leg A* paths into a
+    /// single route. `pin_first`/`pin_last` hold the first and/or last stop
+    /// fixed (e.g. "leave home, scout these systems, return home") and only
+    /// permute the stops in between. Returns `None` if any required leg is
+    /// unreachable for `fleet`, or if there are more than
+    /// `MAX_TOUR_WAYPOINTS` free stops to order.
+    pub fn plan_tour(
+        &self,
+        start: &System,
+        waypoints: &[System],
+        fleet: &Fleet,
+        pin_first: bool,
+        pin_last: bool,
+    ) -> Option<Vec<System>> {
+        let mut stops = Vec::with_capacity(1 + waypoints.len());
+        stops.push(start.clone());
+        stops.extend(waypoints.iter().cloned());
+
+        let n = stops.len();
+        if n < 2 {
+            return Some(stops);
+        }
+
+        let fixed_first = pin_first.then_some(0);
+        let fixed_last = pin_last.then_some(n - 1);
+
+        let mut middle: Vec<usize> = (0..n)
+            .filter(|&i| Some(i) != fixed_first && Some(i) != fixed_last)
+            .collect();
+
+        // Reject oversized tours up front, before building the O(n^2)
+        // pairwise-astar cost matrix below; the cap bounds the factorial
+        // permutation search over `middle`.
+        if middle.len() > Self::MAX_TOUR_WAYPOINTS {
+            return None;
+        }
+
+        // Pairwise shortest paths between every stop. A missing pairing is
+        // recorded as infinite cost so an unreachable waypoint aborts the
+        // tour rather than being silently skipped.
+        let mut leg_cost: HashMap<(usize, usize), u32> = HashMap::new();
+        let mut leg_path: HashMap<(usize, usize), Vec<System>> = HashMap::new();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                match self.astar(&stops[i], &stops[j], fleet) {
+                    Some(path) => {
+                        leg_cost.insert((i, j), self.path_weight(&path));
+                        leg_path.insert((i, j), path);
+                    }
+                    None => {
+                        leg_cost.insert((i, j), u32::MAX);
+                    }
+                }
+            }
+        }
+
+        let mut best_cost = u32::MAX;
+        let mut best_order: Option<Vec<usize>> = None;
+
+        loop {
+            let order: Vec<usize> = fixed_first
+                .into_iter()
+                .chain(middle.iter().copied())
+                .chain(fixed_last)
+                .collect();
+
+            if let Some(total) = Self::order_cost(&order, &leg_cost) {
+                if total < best_cost {
+                    best_cost = total;
+                    best_order = Some(order);
+                }
+            }
+
+            if !Self::next_permutation(&mut middle) {
+                break;
+            }
+        }
+
+        let order = best_order?;
+
+        // Stitch the stored sub-paths together, dropping the duplicated
+        // junction system between consecutive legs.
+        let mut full_path = vec![stops[order[0]].clone()];
+        for pair in order.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let key = (a.min(b), a.max(b));
+            let leg = leg_path.get(&key).expect("feasible leg missing sub-path");
+            if a <= b {
+                full_path.extend(leg.iter().skip(1).cloned());
+            } else {
+                full_path.extend(leg.iter().rev().skip(1).cloned());
+            }
+        }
+
+        Some(full_path)
+    }
+
+    fn order_cost(order: &[usize], leg_cost: &HashMap<(usize, usize), u32>) -> Option<u32> {
+        let mut total = 0u32;
+        for pair in order.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let cost = *leg_cost.get(&(a.min(b), a.max(b)))?;
+            if cost == u32::MAX {
+                return None;
+            }
+            total = total.checked_add(cost)?;
+        }
+        Some(total)
+    }
+
+    /// Sums lane weights along an already-found path of adjacent systems.
+    fn path_weight(&self, path: &[System]) -> u32 {
+        path.windows(2)
+            .map(|pair| {
+                let a = *self.system_id_to_node.get(&pair[0].id).unwrap();
+                let b = *self.system_id_to_node.get(&pair[1].id).unwrap();
+                let edge = self
+                    .graph
+                    .find_edge(a, b)
+                    .expect("path edge not found in graph");
+                self.graph[edge].weight()
+            })
+            .sum()
+    }
+
+    /// Advances `indices` to the lexically next permutation in place.
+    /// Returns `false` once every permutation has been produced (leaving
+    /// `indices` sorted descending).
+    fn next_permutation(indices: &mut [usize]) -> bool {
+        if indices.len() < 2 {
+            return false;
+        }
+        let mut i = indices.len() - 1;
+        while i > 0 && indices[i - 1] >= indices[i] {
+            i -= 1;
+        }
+        if i == 0 {
+            return false;
+        }
+        let pivot = i - 1;
+        let mut j = indices.len() - 1;
+        while indices[j] <= indices[pivot] {
+            j -= 1;
+        }
+        indices.swap(pivot, j);
+        indices[pivot + 1..].reverse();
+        true
+    }
+
+    /// Runs a single Dijkstra expansion from `origin` over edges allowed
+    /// for `traversal_class`, caching the cost and route back to every
+    /// reachable system. Meant for turn maintenance, where re-running A*
+    /// from the same origins every fleet/turn is wasteful.
+    pub fn precompute(&self, origin: usize, traversal_class: TraversalClass) -> PrecomputedRoutes {
+        let mut cost: HashMap<usize, u32> = HashMap::new();
+        let mut predecessor: HashMap<usize, usize> = HashMap::new();
+        let mut open_set = BinaryHeap::new();
+
+        cost.insert(origin, 0);
+        open_set.push(Reverse((0u32, origin)));
+
+        while let Some(Reverse((dist, current))) = open_set.pop() {
+            if dist > *cost.get(&current).unwrap_or(&u32::MAX) {
+                continue;
+            }
+
+            let current_node = *self.system_id_to_node.get(&current).unwrap();
+            for edge in self.graph.edges(current_node) {
+                let lane_type = *edge.weight();
+                if !traversal_class.allows(lane_type) {
+                    continue;
+                }
+
+                let neighbor = self.get_system_id_by_node_index(edge.target());
+                let tentative_cost = dist + lane_type.weight();
+                if tentative_cost < *cost.get(&neighbor).unwrap_or(&u32::MAX) {
+                    cost.insert(neighbor, tentative_cost);
+                    predecessor.insert(neighbor, current);
+                    open_set.push(Reverse((tentative_cost, neighbor)));
+                }
+            }
+        }
+
+        let systems = cost
+            .keys()
+            .map(|&id| (id, self.systems[&id].clone()))
+            .collect();
+
+        PrecomputedRoutes {
+            origin,
+            traversal_class,
+            lane_fingerprint: self.lane_fingerprint(),
+            cost,
+            predecessor,
+            systems,
+        }
+    }
+
+    /// Hash of the current lane set, independent of lane insertion order.
+    /// Used to detect a [`PrecomputedRoutes`] cache that was saved against
+    /// a galaxy that has since been regenerated.
+    pub fn lane_fingerprint(&self) -> u64 {
+        let mut entries: Vec<(usize, usize, u8)> = self
+            .lanes
+            .iter()
+            .map(|lane| (lane.source, lane.destination, lane.lane_type as u8))
+            .collect();
+        entries.sort_unstable();
+
+        let mut hasher = DefaultHasher::new();
+        entries.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Single-source distance/route cache produced by [`StarMap::precompute`],
+/// keyed by [`TraversalClass`] so turn maintenance doesn't re-run Dijkstra
+/// from the same origin for every fleet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrecomputedRoutes {
+    origin: usize,
+    traversal_class: TraversalClass,
+    lane_fingerprint: u64,
+    cost: HashMap<usize, u32>,
+    predecessor: HashMap<usize, usize>,
+    systems: HashMap<usize, System>,
+}
+
+impl PrecomputedRoutes {
+    pub fn cost_to(&self, dest: usize) -> Option<u32> {
+        self.cost.get(&dest).copied()
+    }
+
+    pub fn path_to(&self, dest: usize) -> Option<Vec<System>> {
+        if !self.cost.contains_key(&dest) {
+            return None;
+        }
+
+        let mut ids = vec![dest];
+        let mut current = dest;
+        while let Some(&prev) = self.predecessor.get(&current) {
+            ids.push(prev);
+            current = prev;
+        }
+        ids.reverse();
+
+        Some(ids.into_iter().map(|id| self.systems[&id].clone()).collect())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> bincode::Result<()> {
+        let file = std::fs::File::create(path)?;
+        bincode::serialize_into(file, self)
+    }
+
+    /// Loads a cache from `path`, rejecting it if `expected_fingerprint`
+    /// (typically the current [`StarMap::lane_fingerprint`]) doesn't match
+    /// the fingerprint stored at save time, i.e. the galaxy was
+    /// regenerated since this cache was written.
+    pub fn load(path: impl AsRef<Path>, expected_fingerprint: u64) -> bincode::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let routes: PrecomputedRoutes = bincode::deserialize_from(file)?;
+
+        if routes.lane_fingerprint != expected_fingerprint {
+            return Err(Box::new(bincode::ErrorKind::Custom(
+                "stale PrecomputedRoutes cache: lane fingerprint does not match current map"
+                    .to_string(),
+            )));
+        }
+
+        Ok(routes)
+    }
 }
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::Hex;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct System {
     pub id: usize,
     pub coords: Hex,
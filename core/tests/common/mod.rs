@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use ec4x_core::{Fleet, Hex, LaneType, Ship, ShipType, StarMap, System};
+
+/// `num_rings` used by every fixture `StarMap` below; kept fixed so
+/// `System::new`'s `Hex::to_id` is consistent across fixtures.
+const NUM_RINGS: u32 = 2;
+
+/// A minimal `a - b - c` chain, built directly with `add_system`/`add_lane`
+/// instead of `populate`/`generate_lanes`, so the topology (and which
+/// systems are reachable from which) is deterministic. `bc_lane_type`
+/// parameterizes the `b -> c` lane so callers that care about lane-type
+/// behavior (e.g. traversal-class filtering) can pick one.
+pub fn chain_map(bc_lane_type: LaneType) -> (StarMap, System, System, System) {
+    let mut star_map = StarMap::new(NUM_RINGS as usize);
+
+    let a = System::new(Hex::new(0, 0), 0, NUM_RINGS, None);
+    let b = System::new(Hex::new(1, 0), 1, NUM_RINGS, None);
+    let c = System::new(Hex::new(2, 0), 2, NUM_RINGS, None);
+
+    star_map.add_system(a.clone());
+    star_map.add_system(b.clone());
+    star_map.add_system(c.clone());
+
+    star_map.add_lane(a.id, b.id, LaneType::Major);
+    star_map.add_lane(b.id, c.id, bc_lane_type);
+
+    (star_map, a, b, c)
+}
+
+/// `chain_map` plus a `d` system with no lanes to anything, for tests that
+/// need a waypoint that's unreachable from the rest of the map.
+pub fn chain_map_with_disconnected(
+    bc_lane_type: LaneType,
+) -> (StarMap, System, System, System, System) {
+    let (mut star_map, a, b, c) = chain_map(bc_lane_type);
+
+    let d = System::new(Hex::new(-2, -2), 4, NUM_RINGS, None);
+    star_map.add_system(d.clone());
+
+    (star_map, a, b, c, d)
+}
+
+/// A single uncrippled military ship, which can traverse every lane type.
+pub fn any_fleet() -> Fleet {
+    Fleet {
+        ships: vec![Ship::new(ShipType::Military, false)],
+    }
+}
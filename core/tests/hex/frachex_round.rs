@@ -0,0 +1,26 @@
+use ec4x_core::hex::FracHex;
+use ec4x_core::Hex;
+
+#[test]
+fn round_is_identity_on_integer_coordinates() {
+    let hex = Hex::new(3, -2);
+    let frac = FracHex::new(hex.q as f64, hex.r as f64);
+    assert_eq!(frac.round(), hex);
+}
+
+#[test]
+fn round_fixes_up_x_when_its_delta_is_largest() {
+    assert_eq!(FracHex::new(0.4, 1.3).round(), Hex::new(1, 1));
+}
+
+#[test]
+fn round_fixes_up_y_when_its_delta_is_largest() {
+    assert_eq!(FracHex::new(1.3, 0.3).round(), Hex::new(1, 0));
+}
+
+#[test]
+fn round_fixes_up_z_on_a_tied_delta() {
+    // x_diff and z_diff are tied here, so the fixup falls through past
+    // both `if`/`else if` branches to the final `else`, correcting z.
+    assert_eq!(FracHex::new(0.6, 0.6).round(), Hex::new(1, 0));
+}
@@ -0,0 +1,34 @@
+use ec4x_core::Hex;
+
+#[test]
+fn line_to_samples_are_pairwise_adjacent() {
+    let cases = [
+        (Hex::new(0, 0), Hex::new(5, -3)),
+        (Hex::new(-2, 4), Hex::new(3, 1)),
+        (Hex::new(0, 0), Hex::new(0, 6)),
+    ];
+
+    for (from, to) in cases {
+        let line = from.line_to(&to);
+        for pair in line.windows(2) {
+            assert_eq!(pair[0].distance(&pair[1]), 1);
+        }
+    }
+}
+
+#[test]
+fn line_to_starts_and_ends_at_the_endpoints() {
+    let from = Hex::new(1, -1);
+    let to = Hex::new(4, 2);
+    let line = from.line_to(&to);
+
+    assert_eq!(*line.first().unwrap(), from);
+    assert_eq!(*line.last().unwrap(), to);
+    assert_eq!(line.len() as u32, from.distance(&to) + 1);
+}
+
+#[test]
+fn line_to_self_is_a_single_point() {
+    let hex = Hex::new(2, 2);
+    assert_eq!(hex.line_to(&hex), vec![hex]);
+}
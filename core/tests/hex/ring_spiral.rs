@@ -0,0 +1,50 @@
+use ec4x_core::Hex;
+
+#[test]
+fn ring_radius_zero_yields_only_center() {
+    let center = Hex::new(3, -1);
+    let hexes: Vec<Hex> = Hex::ring(&center, 0).collect();
+    assert_eq!(hexes, vec![center]);
+}
+
+#[test]
+fn ring_negative_radius_yields_empty() {
+    let center = Hex::new(0, 0);
+    let hexes: Vec<Hex> = Hex::ring(&center, -1).collect();
+    assert!(hexes.is_empty());
+}
+
+#[test]
+fn ring_radius_one_has_six_distinct_neighbors() {
+    let center = Hex::new(0, 0);
+    let hexes: Vec<Hex> = Hex::ring(&center, 1).collect();
+    assert_eq!(hexes.len(), 6);
+    for hex in &hexes {
+        assert_eq!(hex.distance(&center), 1);
+    }
+}
+
+#[test]
+fn spiral_radius_zero_yields_only_center() {
+    let center = Hex::new(2, 2);
+    let hexes: Vec<Hex> = Hex::spiral(&center, 0).collect();
+    assert_eq!(hexes, vec![center]);
+}
+
+#[test]
+fn spiral_negative_radius_yields_empty() {
+    let center = Hex::new(0, 0);
+    let hexes: Vec<Hex> = Hex::spiral(&center, -1).collect();
+    assert!(hexes.is_empty());
+}
+
+#[test]
+fn spiral_matches_within_radius() {
+    let center = Hex::new(0, 0);
+    let mut spiral: Vec<Hex> = Hex::spiral(&center, 2).collect();
+    let mut disc = Hex::within_radius(&center, 2);
+
+    spiral.sort();
+    disc.sort();
+    assert_eq!(spiral, disc);
+}
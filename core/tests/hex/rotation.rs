@@ -0,0 +1,35 @@
+use ec4x_core::Hex;
+
+#[test]
+fn rotate_left_then_right_is_identity() {
+    let hexes = [
+        Hex::new(0, 0),
+        Hex::new(3, -2),
+        Hex::new(-4, 1),
+        Hex::new(2, 2),
+        Hex::new(-1, -1),
+    ];
+
+    for hex in hexes {
+        assert_eq!(hex.rotate_left().rotate_right(), hex);
+        assert_eq!(hex.rotate_right().rotate_left(), hex);
+    }
+}
+
+#[test]
+fn six_left_rotations_is_identity() {
+    let hex = Hex::new(2, -3);
+    let mut rotated = hex;
+    for _ in 0..6 {
+        rotated = rotated.rotate_left();
+    }
+    assert_eq!(rotated, hex);
+}
+
+#[test]
+fn rotation_preserves_distance_from_origin() {
+    let hex = Hex::new(4, -1);
+    let origin = Hex::new(0, 0);
+    assert_eq!(hex.rotate_left().distance(&origin), hex.distance(&origin));
+    assert_eq!(hex.rotate_right().distance(&origin), hex.distance(&origin));
+}
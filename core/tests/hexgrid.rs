@@ -0,0 +1,47 @@
+use ec4x_core::{Hex, HexGrid};
+
+#[test]
+fn get_and_set_round_trip_in_bounds() {
+    let mut grid: HexGrid<i32> = HexGrid::new(2, 0);
+    let hex = Hex::new(1, 0);
+
+    assert!(grid.set(hex, 42));
+    assert_eq!(grid.get(hex), Some(&42));
+}
+
+#[test]
+fn get_out_of_bounds_returns_none() {
+    let grid: HexGrid<i32> = HexGrid::new(2, 0);
+    let out_of_bounds = Hex::new(5, 5);
+
+    assert!(!grid.is_valid(out_of_bounds));
+    assert_eq!(grid.get(out_of_bounds), None);
+}
+
+#[test]
+fn set_out_of_bounds_returns_false_and_does_not_panic() {
+    let mut grid: HexGrid<i32> = HexGrid::new(2, 0);
+    let out_of_bounds = Hex::new(5, 5);
+
+    assert!(!grid.set(out_of_bounds, 99));
+    assert_eq!(grid.get(out_of_bounds), None);
+}
+
+#[test]
+fn get_mut_out_of_bounds_returns_none() {
+    let mut grid: HexGrid<i32> = HexGrid::new(2, 0);
+    let out_of_bounds = Hex::new(-5, -5);
+
+    assert!(grid.get_mut(out_of_bounds).is_none());
+}
+
+#[test]
+fn iter_only_yields_in_bounds_cells() {
+    let grid: HexGrid<i32> = HexGrid::new(1, 7);
+    let count = grid.iter().count();
+
+    // A radius-1 hexagonal board has 1 (center) + 6 (ring 1) = 7 cells,
+    // excluding the backing array's out-of-bounds corners.
+    assert_eq!(count, 7);
+    assert!(grid.iter().all(|(hex, &value)| { grid.is_valid(hex) && value == 7 }));
+}
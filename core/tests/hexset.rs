@@ -0,0 +1,56 @@
+use ec4x_core::{Hex, HexSet};
+
+#[test]
+fn border_excludes_fully_surrounded_members() {
+    // A filled radius-1 disc: the center has all 6 neighbors in the set,
+    // so only the outer ring (each missing at least one neighbor) borders.
+    let center = Hex::new(0, 0);
+    let disc = HexSet::from_hexes(Hex::within_radius(&center, 1));
+
+    let border = disc.border();
+
+    assert!(!border.contains(&center));
+    for hex in Hex::ring(&center, 1) {
+        assert!(border.contains(&hex));
+    }
+    assert_eq!(border.len(), 6);
+}
+
+#[test]
+fn expand_unions_within_radius_of_every_member() {
+    let center = Hex::new(0, 0);
+    let set = HexSet::from_hexes([center]);
+
+    let expanded = set.expand(1);
+    let expected = HexSet::from_hexes(Hex::within_radius(&center, 1));
+
+    assert_eq!(expanded, expected);
+}
+
+#[test]
+fn centroid_of_empty_set_is_none() {
+    assert_eq!(HexSet::new().centroid(), None);
+}
+
+#[test]
+fn centroid_of_symmetric_set_is_its_center() {
+    let center = Hex::new(2, -1);
+    let set = HexSet::from_hexes(Hex::ring(&center, 1));
+
+    assert_eq!(set.centroid(), Some(center));
+}
+
+#[test]
+fn bounding_box_of_empty_set_is_none() {
+    assert_eq!(HexSet::new().bounding_box(), None);
+}
+
+#[test]
+fn bounding_box_covers_every_member() {
+    let set = HexSet::from_hexes([Hex::new(-3, 2), Hex::new(4, -1), Hex::new(0, 0)]);
+
+    let (min, max) = set.bounding_box().expect("non-empty set has a bounding box");
+
+    assert_eq!(min, Hex::new(-3, -1));
+    assert_eq!(max, Hex::new(4, 2));
+}
@@ -0,0 +1,38 @@
+use ec4x_core::{Hex, Layout, Orientation};
+
+fn sample_hexes() -> Vec<Hex> {
+    vec![
+        Hex::new(0, 0),
+        Hex::new(3, -2),
+        Hex::new(-4, 1),
+        Hex::new(2, 2),
+        Hex::new(-1, -1),
+    ]
+}
+
+#[test]
+fn pointy_top_round_trips_pixel_and_hex() {
+    let layout = Layout::new(Orientation::PointyTop, (10.0, 10.0), (0.0, 0.0));
+    for hex in sample_hexes() {
+        let pixel = layout.hex_to_pixel(hex);
+        assert_eq!(layout.pixel_to_hex(pixel), hex);
+    }
+}
+
+#[test]
+fn flat_top_round_trips_pixel_and_hex() {
+    let layout = Layout::new(Orientation::FlatTop, (10.0, 10.0), (0.0, 0.0));
+    for hex in sample_hexes() {
+        let pixel = layout.hex_to_pixel(hex);
+        assert_eq!(layout.pixel_to_hex(pixel), hex);
+    }
+}
+
+#[test]
+fn round_trips_with_nonzero_origin() {
+    let layout = Layout::new(Orientation::PointyTop, (12.5, 8.0), (100.0, -50.0));
+    for hex in sample_hexes() {
+        let pixel = layout.hex_to_pixel(hex);
+        assert_eq!(layout.pixel_to_hex(pixel), hex);
+    }
+}
@@ -0,0 +1,78 @@
+use ec4x_core::{Fleet, Hex, LaneType, MoveMetric, Ship, ShipType, StarMap, System};
+
+/// A `s - a - b - c` chain of `Major` (weight 1) lanes, plus a direct
+/// `s - d` `Restricted` (weight 3) lane. The chain gives a clean
+/// cost-equals-budget boundary to probe; the restricted spur gives a lane
+/// whose `Weighted` and `Hops` cost diverge (3 vs 1), and whose
+/// reachability depends on the fleet's ability to cross it.
+fn spur_map() -> (StarMap, System, System, System, System, System) {
+    let num_rings = 2;
+    let mut star_map = StarMap::new(num_rings as usize);
+
+    let s = System::new(Hex::new(0, 0), 0, num_rings, None);
+    let a = System::new(Hex::new(1, 0), 1, num_rings, None);
+    let b = System::new(Hex::new(2, 0), 2, num_rings, None);
+    let c = System::new(Hex::new(2, 1), 3, num_rings, None);
+    let d = System::new(Hex::new(0, 1), 1, num_rings, None);
+
+    star_map.add_system(s.clone());
+    star_map.add_system(a.clone());
+    star_map.add_system(b.clone());
+    star_map.add_system(c.clone());
+    star_map.add_system(d.clone());
+
+    star_map.add_lane(s.id, a.id, LaneType::Major);
+    star_map.add_lane(a.id, b.id, LaneType::Major);
+    star_map.add_lane(b.id, c.id, LaneType::Major);
+    star_map.add_lane(s.id, d.id, LaneType::Restricted);
+
+    (star_map, s, a, b, c, d)
+}
+
+fn military_fleet() -> Fleet {
+    Fleet {
+        ships: vec![Ship::new(ShipType::Military, false)],
+    }
+}
+
+fn spacelift_fleet() -> Fleet {
+    Fleet {
+        ships: vec![Ship::new(ShipType::Spacelift, false)],
+    }
+}
+
+#[test]
+fn reachable_includes_cost_equal_to_budget_excludes_cost_over_budget() {
+    let (star_map, s, _a, b, c, _d) = spur_map();
+    let fleet = military_fleet();
+
+    let result = star_map.reachable(&s, &fleet, 2, MoveMetric::Weighted);
+
+    assert_eq!(result.get(&b.id), Some(&2));
+    assert_eq!(result.get(&c.id), None);
+}
+
+#[test]
+fn weighted_and_hops_metrics_diverge_on_the_restricted_spur() {
+    let (star_map, s, _a, _b, _c, d) = spur_map();
+    let fleet = military_fleet();
+
+    let weighted = star_map.reachable(&s, &fleet, 1, MoveMetric::Weighted);
+    let hops = star_map.reachable(&s, &fleet, 1, MoveMetric::Hops);
+
+    // The restricted lane costs 3 under `Weighted` (too expensive for a
+    // budget of 1) but only 1 hop under `Hops` (just affordable).
+    assert_eq!(weighted.get(&d.id), None);
+    assert_eq!(hops.get(&d.id), Some(&1));
+}
+
+#[test]
+fn reachable_excludes_lanes_the_fleet_cannot_traverse() {
+    let (star_map, s, _a, _b, _c, d) = spur_map();
+    let fleet = spacelift_fleet();
+
+    let result = star_map.reachable(&s, &fleet, 10, MoveMetric::Hops);
+
+    assert_eq!(result.get(&d.id), None);
+    assert_eq!(result.get(&s.id), Some(&0));
+}
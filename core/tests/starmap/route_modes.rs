@@ -0,0 +1,84 @@
+use ec4x_core::{Fleet, Hex, LaneType, RouteMode, Ship, ShipType, StarMap, System};
+
+/// `s` has two ways to reach `g`: a direct but `Restricted` (weight 3) lane,
+/// and a two-hop `Major` (weight 1 each) detour through `m` that's cheaper
+/// overall (weight 2) but longer. `RouteMode::Bfs` ignores lane weight and
+/// should take the direct one-hop lane; `RouteMode::AStar` should take the
+/// cheaper two-hop detour.
+fn fork_map() -> (StarMap, System, System, System) {
+    let num_rings = 2;
+    let mut star_map = StarMap::new(num_rings as usize);
+
+    let s = System::new(Hex::new(0, 0), 0, num_rings, None);
+    let m = System::new(Hex::new(1, 0), 1, num_rings, None);
+    let g = System::new(Hex::new(2, 0), 2, num_rings, None);
+
+    star_map.add_system(s.clone());
+    star_map.add_system(m.clone());
+    star_map.add_system(g.clone());
+
+    star_map.add_lane(s.id, g.id, LaneType::Restricted);
+    star_map.add_lane(s.id, m.id, LaneType::Major);
+    star_map.add_lane(m.id, g.id, LaneType::Major);
+
+    (star_map, s, m, g)
+}
+
+fn any_fleet() -> Fleet {
+    Fleet {
+        ships: vec![Ship::new(ShipType::Military, false)],
+    }
+}
+
+#[test]
+fn bfs_prefers_fewest_hops_over_cheapest_weight() {
+    let (star_map, s, _m, g) = fork_map();
+    let fleet = any_fleet();
+
+    let path = star_map
+        .route(&s, &g, &fleet, RouteMode::Bfs, None)
+        .expect("path should be found");
+
+    assert_eq!(path.iter().map(|sys| sys.id).collect::<Vec<_>>(), vec![s.id, g.id]);
+}
+
+#[test]
+fn astar_prefers_cheapest_weight_over_fewest_hops() {
+    let (star_map, s, m, g) = fork_map();
+    let fleet = any_fleet();
+
+    let path = star_map
+        .route(&s, &g, &fleet, RouteMode::AStar, None)
+        .expect("path should be found");
+
+    assert_eq!(
+        path.iter().map(|sys| sys.id).collect::<Vec<_>>(),
+        vec![s.id, m.id, g.id]
+    );
+}
+
+#[test]
+fn greedy_finds_a_path_to_the_goal() {
+    let (star_map, s, _m, g) = fork_map();
+    let fleet = any_fleet();
+
+    let path = star_map
+        .route(&s, &g, &fleet, RouteMode::Greedy, None)
+        .expect("path should be found");
+
+    assert_eq!(path.first().unwrap().id, s.id);
+    assert_eq!(path.last().unwrap().id, g.id);
+}
+
+#[test]
+fn beam_limited_search_still_finds_a_path() {
+    let (star_map, s, _m, g) = fork_map();
+    let fleet = any_fleet();
+
+    let path = star_map
+        .route(&s, &g, &fleet, RouteMode::AStar, Some(1))
+        .expect("beam-limited search should still find some path");
+
+    assert_eq!(path.first().unwrap().id, s.id);
+    assert_eq!(path.last().unwrap().id, g.id);
+}
@@ -0,0 +1,49 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ec4x_core::{LaneType, PrecomputedRoutes, TraversalClass};
+
+#[path = "../common/mod.rs"]
+mod common;
+use common::chain_map;
+
+fn scratch_path(name: &str) -> std::path::PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("ec4x-routes-test-{name}-{nanos}.bin"))
+}
+
+#[test]
+fn precomputed_routes_save_load_round_trip() {
+    let (star_map, a, _b, c) = chain_map(LaneType::Minor);
+    let routes = star_map.precompute(a.id, TraversalClass::Standard);
+    let path = scratch_path("round-trip");
+
+    routes.save(&path).expect("save should succeed");
+    let loaded =
+        PrecomputedRoutes::load(&path, star_map.lane_fingerprint()).expect("load should succeed");
+
+    assert_eq!(loaded.cost_to(c.id), routes.cost_to(c.id));
+    assert_eq!(
+        loaded.path_to(c.id).map(|p| p.iter().map(|s| s.id).collect::<Vec<_>>()),
+        routes.path_to(c.id).map(|p| p.iter().map(|s| s.id).collect::<Vec<_>>()),
+    );
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn precomputed_routes_rejects_stale_fingerprint() {
+    let (star_map, a, _b, _c) = chain_map(LaneType::Minor);
+    let routes = star_map.precompute(a.id, TraversalClass::Standard);
+    let path = scratch_path("stale");
+
+    routes.save(&path).expect("save should succeed");
+    let stale_fingerprint = star_map.lane_fingerprint().wrapping_add(1);
+    let result = PrecomputedRoutes::load(&path, stale_fingerprint);
+
+    assert!(result.is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,62 @@
+use ec4x_core::{LaneType, StarMap, System};
+
+#[path = "../common/mod.rs"]
+mod common;
+use common::{any_fleet, chain_map_with_disconnected};
+
+#[test]
+fn plan_tour_unpinned_finds_best_order() {
+    let (star_map, a, b, c, _d) = chain_map_with_disconnected(LaneType::Major);
+    let fleet = any_fleet();
+
+    let path = star_map
+        .plan_tour(&a, &[c.clone(), b.clone()], &fleet, false, false)
+        .expect("tour should be found");
+
+    // a -> b -> c (cost 2) is the cheapest ordering; its reverse ties it
+    // but is enumerated later, so a -> b -> c is kept.
+    assert_eq!(path.first().unwrap().id, a.id);
+    assert_eq!(path.last().unwrap().id, c.id);
+}
+
+#[test]
+fn plan_tour_pins_first_and_last() {
+    let (star_map, a, b, c, _d) = chain_map_with_disconnected(LaneType::Major);
+    let fleet = any_fleet();
+
+    // pin_last fixes the final waypoint (b) in place even though visiting
+    // c before doubling back through b is more expensive than a -> b -> c.
+    let path = star_map
+        .plan_tour(&a, &[c.clone(), b.clone()], &fleet, true, true)
+        .expect("tour should be found");
+
+    assert_eq!(path.first().unwrap().id, a.id);
+    assert_eq!(path.last().unwrap().id, b.id);
+}
+
+#[test]
+fn plan_tour_unreachable_waypoint_returns_none() {
+    let (star_map, a, b, _c, d) = chain_map_with_disconnected(LaneType::Major);
+    let fleet = any_fleet();
+
+    assert!(star_map
+        .plan_tour(&a, &[b.clone(), d.clone()], &fleet, false, false)
+        .is_none());
+}
+
+#[test]
+fn plan_tour_rejects_too_many_free_waypoints_before_costing_legs() {
+    let (star_map, a, b, _c, _d) = chain_map_with_disconnected(LaneType::Major);
+    let fleet = any_fleet();
+
+    // `b` repeated past `MAX_TOUR_WAYPOINTS` as free (unpinned) waypoints:
+    // the cap must reject this before running any pairwise A*, regardless
+    // of whether the repeated waypoint is actually reachable.
+    let waypoints: Vec<System> = std::iter::repeat(b)
+        .take(StarMap::MAX_TOUR_WAYPOINTS + 1)
+        .collect();
+
+    assert!(star_map
+        .plan_tour(&a, &waypoints, &fleet, false, false)
+        .is_none());
+}
@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use ec4x_core::{StarMap, TraversalClass};
+
+use crate::config;
+
+/// Filename the generated galaxy is saved under, alongside the game's
+/// config file.
+pub const STARMAP_FILE: &str = "starmap.bin";
+
+/// Filename the hub's [`PrecomputedRoutes`](ec4x_core::PrecomputedRoutes)
+/// cache is saved under for the given [`TraversalClass`], alongside the
+/// game's config file.
+pub fn routes_file(traversal_class: TraversalClass) -> PathBuf {
+    let name = match traversal_class {
+        TraversalClass::Standard => "routes_standard.bin",
+        TraversalClass::RestrictedCapable => "routes_restricted.bin",
+    };
+    PathBuf::from(name)
+}
+
+/// Recomputes the hub's route cache for every [`TraversalClass`] and saves
+/// each to `dir`, so `Maint` can reload rather than re-running Dijkstra for
+/// every fleet every turn.
+pub fn save_precomputed_routes(dir: &Path, star_map: &StarMap) -> Result<()> {
+    for traversal_class in [TraversalClass::Standard, TraversalClass::RestrictedCapable] {
+        let routes = star_map.precompute(star_map.hub_id(), traversal_class);
+        let routes_path = dir.join(routes_file(traversal_class));
+        routes.save(&routes_path).with_context(|| {
+            format!("Could not save route cache to `{}`", routes_path.display())
+        })?;
+    }
+    Ok(())
+}
+
+/// Generates a galaxy for the game in `dir` and saves it, along with its
+/// hub route cache, so later `Start`/`Maint` invocations load the same
+/// data rather than regenerating it.
+pub fn new_game(dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Could not create game directory `{}`", dir.display()))?;
+
+    let config = config::load_config(dir.to_path_buf())?;
+
+    let mut star_map = StarMap::new(config.num_empires as usize);
+    star_map.populate();
+    star_map.generate_lanes();
+
+    let map_path = dir.join(STARMAP_FILE);
+    star_map
+        .save(&map_path)
+        .with_context(|| format!("Could not save starmap to `{}`", map_path.display()))?;
+
+    save_precomputed_routes(dir, &star_map)?;
+
+    println!("Generated galaxy saved to {}", map_path.display());
+    Ok(())
+}
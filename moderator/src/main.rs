@@ -2,8 +2,9 @@ use std::env;
 #[allow(dead_code)]
 use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+use ec4x_core::{PrecomputedRoutes, StarMap, TraversalClass};
 
 extern crate self as moderator;
 mod config;
@@ -23,18 +24,56 @@ enum Commands {
     /// Initialize a new game in <DIR>
     New { dir: PathBuf },
     /// Start the server for game located at <DIR>
-    Start { _dir: PathBuf },
+    Start { dir: PathBuf },
     /// Run turn maintenance on game located at <DIR>
-    Maint { _dir: PathBuf },
+    Maint { dir: PathBuf },
     /// Display game stastics for game located at <DIR>
     Stats { _dir: PathBuf },
 }
 
+fn load_starmap(dir: &PathBuf) -> Result<StarMap> {
+    let map_path = dir.join(create::STARMAP_FILE);
+    StarMap::load(&map_path)
+        .with_context(|| format!("Could not load starmap from `{}`", map_path.display()))
+}
+
+/// Loads the hub's `traversal_class` route cache for `star_map`, recomputing
+/// and re-saving it if it's missing or stale (i.e. the galaxy's lanes were
+/// regenerated since the cache was last written).
+fn load_or_refresh_routes(
+    dir: &PathBuf,
+    star_map: &StarMap,
+    traversal_class: TraversalClass,
+) -> Result<PrecomputedRoutes> {
+    let routes_path = dir.join(create::routes_file(traversal_class));
+    let fingerprint = star_map.lane_fingerprint();
+
+    if let Ok(routes) = PrecomputedRoutes::load(&routes_path, fingerprint) {
+        return Ok(routes);
+    }
+
+    let routes = star_map.precompute(star_map.hub_id(), traversal_class);
+    routes
+        .save(&routes_path)
+        .with_context(|| format!("Could not save route cache to `{}`", routes_path.display()))?;
+    Ok(routes)
+}
+
 fn parse_args(args: Args) -> Result<()> {
     match &args.command {
         Commands::New { dir } => create::new_game(dir),
-        Commands::Start { _dir } => Ok(()),
-        Commands::Maint { _dir } => Ok(()),
+        Commands::Start { dir } => {
+            let _star_map = load_starmap(dir)?;
+            Ok(())
+        }
+        Commands::Maint { dir } => {
+            let star_map = load_starmap(dir)?;
+            let _standard_routes =
+                load_or_refresh_routes(dir, &star_map, TraversalClass::Standard)?;
+            let _restricted_routes =
+                load_or_refresh_routes(dir, &star_map, TraversalClass::RestrictedCapable)?;
+            Ok(())
+        }
         Commands::Stats { _dir } => Ok(()),
     }
 }